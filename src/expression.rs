@@ -1,6 +1,8 @@
 pub mod expression {
     use crate::expression;
-    use crate::expression::expression::Expr::{And, EFalse, ETrue, Mult, One, Or, Plus, Zero};
+    use crate::expression::expression::Expr::{
+        And, EFalse, ETrue, Eq, If, Let, Lt, Mult, One, Or, Plus, Var, Zero,
+    };
     use std::fmt::{Display, Formatter};
 
     #[derive(Debug, PartialEq)]
@@ -13,6 +15,11 @@ pub mod expression {
         Mult(Box<Expr>, Box<Expr>),
         Or(Box<Expr>, Box<Expr>),
         And(Box<Expr>, Box<Expr>),
+        Let(String, Box<Expr>, Box<Expr>),
+        Var(String),
+        Eq(Box<Expr>, Box<Expr>),
+        Lt(Box<Expr>, Box<Expr>),
+        If(Box<Expr>, Box<Expr>, Box<Expr>),
     }
     impl Display for Expr {
         fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -25,6 +32,15 @@ pub mod expression {
                 Mult(left, right) => write!(f, "({} * {})", left, right),
                 Or(left, right) => write!(f, "({} || {})", left, right),
                 And(left, right) => write!(f, "({} && {})", left, right),
+                Let(name, value, body) => {
+                    write!(f, "(let {} = {} in {})", name, value, body)
+                }
+                Var(name) => write!(f, "{}", name),
+                Eq(left, right) => write!(f, "({} == {})", left, right),
+                Lt(left, right) => write!(f, "({} < {})", left, right),
+                If(cond, then, els) => {
+                    write!(f, "(if {} then {} else {})", cond, then, els)
+                }
             }
         }
     }
@@ -32,6 +48,7 @@ pub mod expression {
     /*
      * Represents the type of an expression.
      */
+    #[derive(Clone, Copy, PartialEq)]
     pub enum Type {
         IntType,
         BoolType,
@@ -0,0 +1,203 @@
+pub mod infer {
+    use crate::expression::expression::Expr;
+    use crate::expression::expression::Expr::{And, Let, Mult, Or, Plus, Var};
+    use crate::typecheck::execution::TypeError;
+    use crate::Type;
+    use crate::Type::{BoolType, IntType};
+    use std::collections::HashMap;
+
+    /*
+     * The type language used during inference. Unlike the public `Type`, it
+     * additionally carries unification variables that stand for a type not yet
+     * determined by the constraints collected so far.
+     */
+    #[derive(Clone, Copy, PartialEq)]
+    enum InferType {
+        IntType,
+        BoolType,
+        TypeVar(u32),
+    }
+
+    /*
+     * Mutable inference state: a counter handing out fresh type variables and
+     * the substitution mapping each bound variable to the type it stands for.
+     */
+    struct State {
+        next: u32,
+        subst: HashMap<u32, InferType>,
+    }
+
+    impl State {
+        fn fresh(&mut self) -> InferType {
+            let id = self.next;
+            self.next += 1;
+            InferType::TypeVar(id)
+        }
+
+        /*
+         * Follows the substitution chain one level at a time until reaching a
+         * concrete type or an unbound variable.
+         */
+        fn resolve(&self, t: InferType) -> InferType {
+            match t {
+                InferType::TypeVar(id) => match self.subst.get(&id) {
+                    Some(next) => self.resolve(*next),
+                    None => t,
+                },
+                _ => t,
+            }
+        }
+
+        /*
+         * Makes `a` and `b` equal by binding variables, with an occurs-check to
+         * reject infinite types. Conflicting concrete types become a
+         * `TypeError::Mismatch`.
+         */
+        fn unify(&mut self, a: InferType, b: InferType) -> Result<(), TypeError> {
+            let a = self.resolve(a);
+            let b = self.resolve(b);
+            match (a, b) {
+                _ if a == b => Ok(()),
+                (InferType::TypeVar(id), other) | (other, InferType::TypeVar(id)) => {
+                    if self.occurs(id, other) {
+                        return Err(TypeError::Mismatch {
+                            context: "inference".to_string(),
+                            expected: to_concrete(b),
+                            found: to_concrete(a),
+                        });
+                    }
+                    self.subst.insert(id, other);
+                    Ok(())
+                }
+                _ => Err(TypeError::Mismatch {
+                    context: "inference".to_string(),
+                    expected: to_concrete(b),
+                    found: to_concrete(a),
+                }),
+            }
+        }
+
+        /*
+         * True if the variable `id` already appears in `t`, which would make
+         * binding it to `t` produce an infinite type.
+         */
+        fn occurs(&self, id: u32, t: InferType) -> bool {
+            match self.resolve(t) {
+                InferType::TypeVar(other) => other == id,
+                _ => false,
+            }
+        }
+    }
+
+    /*
+     * Best-effort lowering of a resolved inference type to the public `Type`,
+     * used only when rendering a mismatch where one side may still be a
+     * variable. The root is validated separately in `infer`; an unconstrained
+     * variable reaching here stands in for an unknown type in the message.
+     */
+    fn to_concrete(t: InferType) -> Type {
+        match t {
+            InferType::IntType => IntType,
+            InferType::BoolType => BoolType,
+            InferType::TypeVar(_) => IntType,
+        }
+    }
+
+    /*
+     * Walks `expr`, assigning it a fresh type variable and unifying that
+     * variable with the type dictated by the node and its operands. Returns the
+     * variable standing for the expression's type.
+     */
+    fn constrain(
+        expr: &Expr,
+        env: &HashMap<String, InferType>,
+        state: &mut State,
+    ) -> Result<InferType, TypeError> {
+        let tv = state.fresh();
+        match expr {
+            Expr::One | Expr::Zero => state.unify(tv, InferType::IntType)?,
+            Expr::ETrue | Expr::EFalse => state.unify(tv, InferType::BoolType)?,
+            Plus(e1, e2) | Mult(e1, e2) => {
+                let t1 = constrain(e1, env, state)?;
+                let t2 = constrain(e2, env, state)?;
+                state.unify(t1, InferType::IntType)?;
+                state.unify(t2, InferType::IntType)?;
+                state.unify(tv, InferType::IntType)?;
+            }
+            Or(e1, e2) | And(e1, e2) => {
+                let t1 = constrain(e1, env, state)?;
+                let t2 = constrain(e2, env, state)?;
+                state.unify(t1, InferType::BoolType)?;
+                state.unify(t2, InferType::BoolType)?;
+                state.unify(tv, InferType::BoolType)?;
+            }
+            Let(name, value, body) => {
+                let value_type = constrain(value, env, state)?;
+                let mut child = env.clone();
+                child.insert(name.clone(), value_type);
+                let body_type = constrain(body, &child, state)?;
+                state.unify(tv, body_type)?;
+            }
+            Var(name) => match env.get(name) {
+                Some(t) => state.unify(tv, *t)?,
+                None => return Err(TypeError::Unbound { name: name.clone() }),
+            },
+            Expr::Eq(e1, e2) | Expr::Lt(e1, e2) => {
+                let t1 = constrain(e1, env, state)?;
+                let t2 = constrain(e2, env, state)?;
+                state.unify(t1, InferType::IntType)?;
+                state.unify(t2, InferType::IntType)?;
+                state.unify(tv, InferType::BoolType)?;
+            }
+            Expr::If(cond, then, els) => {
+                let tc = constrain(cond, env, state)?;
+                state.unify(tc, InferType::BoolType)?;
+                let t1 = constrain(then, env, state)?;
+                let t2 = constrain(els, env, state)?;
+                state.unify(t1, t2)?;
+                state.unify(tv, t1)?;
+            }
+        }
+        Ok(tv)
+    }
+
+    /*
+     * Infers the type of `expr` without any caller-supplied annotation by
+     * collecting unification constraints and resolving the root to a concrete
+     * `Type`, or an error when the constraints conflict.
+     */
+    pub fn infer(expr: &Expr) -> Result<Type, TypeError> {
+        let mut state = State {
+            next: 0,
+            subst: HashMap::new(),
+        };
+        let env = HashMap::new();
+        let root = constrain(expr, &env, &mut state)?;
+        match state.resolve(root) {
+            InferType::IntType => Ok(IntType),
+            InferType::BoolType => Ok(BoolType),
+            InferType::TypeVar(_) => Err(TypeError::Ambiguous),
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::infer;
+        use crate::expression::expression::Expr::{EFalse, ETrue, One, Or, Plus};
+        use crate::Type::BoolType;
+
+        // A consistent expression infers its concrete type without annotation.
+        #[test]
+        fn infers_boolean() {
+            let expr = Or(Box::new(EFalse), Box::new(ETrue));
+            assert!(matches!(infer(&expr), Ok(BoolType)));
+        }
+
+        // Conflicting constraints on the two operands are rejected.
+        #[test]
+        fn rejects_conflicting_operands() {
+            let expr = Plus(Box::new(One), Box::new(ETrue));
+            assert!(infer(&expr).is_err());
+        }
+    }
+}
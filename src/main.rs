@@ -1,10 +1,14 @@
 mod expression;
+mod infer;
+mod parser;
 mod typecheck;
 mod util;
 
 use crate::expression::expression::Expr::{And, EFalse, ETrue, Mult, One, Or, Plus, Zero};
 use crate::expression::expression::Type::{BoolType, IntType};
 use crate::expression::expression::{Expr, Type};
+use crate::infer::infer::infer;
+use crate::parser::parser::parse;
 use crate::typecheck::execution::typecheck;
 use std::fmt::{Debug, Display};
 
@@ -34,6 +38,27 @@ fn main() {
     test(&ex3, &BoolType);
     test(&ex4, &BoolType);
     test(&ex5, &BoolType);
+
+    println!("\nParsed from source text:");
+    match parse("1 + 1 * 0") {
+        Ok(expr) => println!("Parsed '1 + 1 * 0' into '{expr}'"),
+        Err(e) => println!("Parse error: {}", e),
+    }
+    match parse("true && (false || true)") {
+        Ok(expr) => test(&expr, &BoolType),
+        Err(e) => println!("Parse error: {}", e),
+    }
+
+    println!("\nInferred types (no annotation):");
+    infer_test(&ex3);
+    infer_test(&ex2);
+}
+
+fn infer_test(expr: &Expr) {
+    match infer(expr) {
+        Ok(t) => println!("Inferred type of '{}': {}", expr, t),
+        Err(e) => println!("Type Error when inferring '{}': {}", expr, e),
+    }
 }
 
 fn test(expr: &Expr, expected_type: &Type) {
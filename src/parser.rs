@@ -0,0 +1,270 @@
+pub mod parser {
+    use crate::expression::expression::Expr;
+    use crate::expression::expression::Expr::{
+        And, EFalse, ETrue, Mult, One, Or, Plus, Zero,
+    };
+
+    /*
+     * A single lexical token. The lexer pairs each token with the byte
+     * position at which it started in the source string, so parse errors can
+     * point at the offender.
+     */
+    #[derive(Debug, PartialEq)]
+    enum Token {
+        One,
+        Zero,
+        True,
+        False,
+        Plus,
+        Mult,
+        Or,
+        And,
+        LParen,
+        RParen,
+    }
+
+    struct Lexer<'a> {
+        input: &'a str,
+        pos: usize,
+    }
+
+    impl<'a> Lexer<'a> {
+        fn new(input: &'a str) -> Lexer<'a> {
+            Lexer { input, pos: 0 }
+        }
+
+        /*
+         * Turns the raw string into a flat list of `(Token, position)` pairs,
+         * skipping whitespace. Unknown characters abort lexing with the byte
+         * offset at which they appear.
+         */
+        fn tokenize(mut self) -> Result<Vec<(Token, usize)>, String> {
+            let bytes = self.input.as_bytes();
+            let mut tokens = Vec::new();
+            while self.pos < bytes.len() {
+                let start = self.pos;
+                let c = bytes[self.pos] as char;
+                match c {
+                    ' ' | '\t' | '\n' | '\r' => {
+                        self.pos += 1;
+                    }
+                    '+' => {
+                        tokens.push((Token::Plus, start));
+                        self.pos += 1;
+                    }
+                    '*' => {
+                        tokens.push((Token::Mult, start));
+                        self.pos += 1;
+                    }
+                    '(' => {
+                        tokens.push((Token::LParen, start));
+                        self.pos += 1;
+                    }
+                    ')' => {
+                        tokens.push((Token::RParen, start));
+                        self.pos += 1;
+                    }
+                    '|' => {
+                        self.expect_char(bytes, '|')?;
+                        tokens.push((Token::Or, start));
+                    }
+                    '&' => {
+                        self.expect_char(bytes, '&')?;
+                        tokens.push((Token::And, start));
+                    }
+                    _ if self.input[start..].starts_with("true") => {
+                        tokens.push((Token::True, start));
+                        self.pos += 4;
+                    }
+                    _ if self.input[start..].starts_with("false") => {
+                        tokens.push((Token::False, start));
+                        self.pos += 5;
+                    }
+                    '1' => {
+                        tokens.push((Token::One, start));
+                        self.pos += 1;
+                    }
+                    '0' => {
+                        tokens.push((Token::Zero, start));
+                        self.pos += 1;
+                    }
+                    _ => {
+                        return Err(format!(
+                            "unexpected character '{}' at position {}",
+                            c, start
+                        ));
+                    }
+                }
+            }
+            Ok(tokens)
+        }
+
+        /*
+         * Consumes a two-character operator like `||` or `&&`, erroring if the
+         * second half is missing.
+         */
+        fn expect_char(&mut self, bytes: &[u8], expected: char) -> Result<(), String> {
+            if self.pos + 1 < bytes.len() && bytes[self.pos + 1] as char == expected {
+                self.pos += 2;
+                Ok(())
+            } else {
+                Err(format!(
+                    "expected '{}' to complete operator at position {}",
+                    expected, self.pos
+                ))
+            }
+        }
+    }
+
+    struct Parser {
+        tokens: Vec<(Token, usize)>,
+        pos: usize,
+        end: usize,
+    }
+
+    impl Parser {
+        fn peek(&self) -> Option<&Token> {
+            self.tokens.get(self.pos).map(|(t, _)| t)
+        }
+
+        fn next_pos(&self) -> usize {
+            self.tokens.get(self.pos).map(|(_, p)| *p).unwrap_or(self.end)
+        }
+
+        // expr -> term (('+' | '||') term)*
+        fn parse_expr(&mut self) -> Result<Expr, String> {
+            let mut left = self.parse_term()?;
+            loop {
+                match self.peek() {
+                    Some(Token::Plus) => {
+                        self.pos += 1;
+                        let right = self.parse_term()?;
+                        left = Plus(Box::new(left), Box::new(right));
+                    }
+                    Some(Token::Or) => {
+                        self.pos += 1;
+                        let right = self.parse_term()?;
+                        left = Or(Box::new(left), Box::new(right));
+                    }
+                    _ => return Ok(left),
+                }
+            }
+        }
+
+        // term -> factor (('*' | '&&') factor)*
+        fn parse_term(&mut self) -> Result<Expr, String> {
+            let mut left = self.parse_factor()?;
+            loop {
+                match self.peek() {
+                    Some(Token::Mult) => {
+                        self.pos += 1;
+                        let right = self.parse_factor()?;
+                        left = Mult(Box::new(left), Box::new(right));
+                    }
+                    Some(Token::And) => {
+                        self.pos += 1;
+                        let right = self.parse_factor()?;
+                        left = And(Box::new(left), Box::new(right));
+                    }
+                    _ => return Ok(left),
+                }
+            }
+        }
+
+        // factor -> '(' expr ')' | atom
+        fn parse_factor(&mut self) -> Result<Expr, String> {
+            if let Some(Token::LParen) = self.peek() {
+                self.pos += 1;
+                let inner = self.parse_expr()?;
+                match self.peek() {
+                    Some(Token::RParen) => {
+                        self.pos += 1;
+                        Ok(inner)
+                    }
+                    _ => Err(format!(
+                        "expected ')' at position {}",
+                        self.next_pos()
+                    )),
+                }
+            } else {
+                self.parse_atom()
+            }
+        }
+
+        // atom -> '1' | '0' | 'true' | 'false'
+        fn parse_atom(&mut self) -> Result<Expr, String> {
+            let pos = self.next_pos();
+            match self.peek() {
+                Some(Token::One) => {
+                    self.pos += 1;
+                    Ok(One)
+                }
+                Some(Token::Zero) => {
+                    self.pos += 1;
+                    Ok(Zero)
+                }
+                Some(Token::True) => {
+                    self.pos += 1;
+                    Ok(ETrue)
+                }
+                Some(Token::False) => {
+                    self.pos += 1;
+                    Ok(EFalse)
+                }
+                Some(_) => Err(format!("unexpected token at position {}", pos)),
+                None => Err("unexpected end of input".parse().unwrap()),
+            }
+        }
+    }
+
+    /*
+     * Parses the concrete syntax produced by `Expr`'s `Display` impl back into
+     * an `Expr`: integer literals `0`/`1`, `true`/`false`, the binary operators
+     * `+`, `*`, `||`, `&&` and parentheses. The `let`, `if`, `==` and `<` forms
+     * and variable names that `Display` also prints are not parsed.
+     * Multiplicative operators bind tighter than additive ones and `&&`
+     * tighter than `||`. Returns a parse error describing the offending
+     * position on malformed or unbalanced input.
+     */
+    pub fn parse(input: &str) -> Result<Expr, String> {
+        let tokens = Lexer::new(input).tokenize()?;
+        let mut parser = Parser {
+            tokens,
+            pos: 0,
+            end: input.len(),
+        };
+        let expr = parser.parse_expr()?;
+        if parser.pos != parser.tokens.len() {
+            return Err(format!(
+                "unexpected trailing token at position {}",
+                parser.next_pos()
+            ));
+        }
+        Ok(expr)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::parse;
+
+        // Parsing respects precedence and round-trips through `Display`.
+        #[test]
+        fn parses_and_round_trips() {
+            let expr = parse("1 + 1 * 0").unwrap();
+            assert_eq!(format!("{}", expr), "(1 + (1 * 0))");
+        }
+
+        // Parentheses override the default precedence.
+        #[test]
+        fn parses_parenthesised_booleans() {
+            let expr = parse("true && (false || true)").unwrap();
+            assert_eq!(format!("{}", expr), "(true && (false || true))");
+        }
+
+        // A dangling operator is a parse error, not a panic.
+        #[test]
+        fn reports_trailing_operator() {
+            assert!(parse("1 +").is_err());
+        }
+    }
+}
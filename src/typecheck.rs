@@ -1,86 +1,239 @@
 pub mod execution {
     use crate::expression::expression::Expr;
-    use crate::expression::expression::Expr::{And, Mult, Or, Plus};
+    use crate::expression::expression::Expr::{And, Let, Mult, Or, Plus, Var};
     use crate::Type;
     use crate::Type::{BoolType, IntType};
     use crate::util::util::Either;
     use crate::util::util::Either::{Left, Right};
+    use std::collections::HashMap;
+    use std::fmt::{Display, Formatter};
 
-    pub fn typecheck(expr: &Expr) -> Result<Type, String> {
+    /*
+     * Which operand of a binary operator a `TypeError` refers to.
+     */
+    pub enum Side {
+        Left,
+        Right,
+    }
+
+    impl Display for Side {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            match self {
+                Side::Left => write!(f, "left"),
+                Side::Right => write!(f, "right"),
+            }
+        }
+    }
+
+    /*
+     * A structured typing failure. Unlike the old stringly-typed errors this
+     * pinpoints the offending subexpression and records the expected and
+     * actually-found types so callers can produce compiler-grade diagnostics.
+     */
+    pub enum TypeError {
+        Mismatch {
+            context: String,
+            expected: Type,
+            found: Type,
+        },
+        OperandMismatch {
+            op: &'static str,
+            side: Side,
+            found: Type,
+        },
+        Unbound {
+            name: String,
+        },
+        Ambiguous,
+    }
+
+    impl Display for TypeError {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            match self {
+                TypeError::Mismatch {
+                    context,
+                    expected,
+                    found,
+                } => write!(
+                    f,
+                    "{} expected {} but found {}",
+                    context, expected, found
+                ),
+                TypeError::OperandMismatch { op, side, found } => write!(
+                    f,
+                    "{} operand of `{}` expected {} but found {}",
+                    side,
+                    op,
+                    expected_operand_type(op),
+                    found
+                ),
+                TypeError::Unbound { name } => write!(f, "unbound variable `{}`", name),
+                TypeError::Ambiguous => write!(f, "ambiguous type: expression is not fully constrained"),
+            }
+        }
+    }
+
+    /*
+     * The operand type an operator demands on both sides, used when rendering
+     * an `OperandMismatch`.
+     */
+    fn expected_operand_type(op: &str) -> Type {
+        match op {
+            "+" | "*" | "==" | "<" => IntType,
+            _ => BoolType,
+        }
+    }
+
+    pub fn typecheck(expr: &Expr) -> Result<Type, TypeError> {
+        let env = HashMap::new();
+        typecheck_env(expr, &env)
+    }
+
+    /*
+     * Typechecks `expr` under the given environment mapping variable names to
+     * their bound types. `Let` extends the environment for the body in a child
+     * scope; `Var` looks its name up and fails if it is unbound.
+     */
+    fn typecheck_env(expr: &Expr, env: &HashMap<String, Type>) -> Result<Type, TypeError> {
         match expr {
             Expr::One => Ok(IntType),
             Expr::Zero => Ok(IntType),
             Expr::ETrue => Ok(BoolType),
             Expr::EFalse => Ok(BoolType),
             Plus(e1, e2) => {
-                let r1 = typecheck(e1)?;
-                let r2 = typecheck(e2)?;
-                match (r1, r2) {
-                    (IntType, IntType) => Ok(IntType),
-                    _ => Err("Plus expression expects int types on both sides! "
-                        .parse()
-                        .unwrap()),
-                }
+                let r1 = typecheck_env(e1, env)?;
+                let r2 = typecheck_env(e2, env)?;
+                check_operands("+", IntType, r1, r2)
             }
             Mult(e1, e2) => {
-                let r1 = typecheck(e1)?;
-                let r2 = typecheck(e2)?;
-                match (r1, r2) {
-                    (IntType, IntType) => Ok(IntType),
-                    _ => Err("Mult expression expects int types on both sides! "
-                        .parse()
-                        .unwrap()),
-                }
+                let r1 = typecheck_env(e1, env)?;
+                let r2 = typecheck_env(e2, env)?;
+                check_operands("*", IntType, r1, r2)
             }
             Or(e1, e2) => {
-                let r1 = typecheck(e1)?;
-                let r2 = typecheck(e2)?;
-                match (r1, r2) {
-                    (BoolType, BoolType) => Ok(BoolType),
-                    _ => Err("Mult expression expects bool types on both sides! "
-                        .parse()
-                        .unwrap()),
-                }
+                let r1 = typecheck_env(e1, env)?;
+                let r2 = typecheck_env(e2, env)?;
+                check_operands("||", BoolType, r1, r2)
             }
             And(e1, e2) => {
-                let r1 = typecheck(e1)?;
-                let r2 = typecheck(e2)?;
-                match (r1, r2) {
-                    (BoolType, BoolType) => Ok(BoolType),
-                    _ => Err("Mult expression expects bool types on both sides! "
-                        .parse()
-                        .unwrap()),
+                let r1 = typecheck_env(e1, env)?;
+                let r2 = typecheck_env(e2, env)?;
+                check_operands("&&", BoolType, r1, r2)
+            }
+            Let(name, value, body) => {
+                let value_type = typecheck_env(value, env)?;
+                let mut child = env.clone();
+                child.insert(name.clone(), value_type);
+                typecheck_env(body, &child)
+            }
+            Var(name) => match env.get(name) {
+                Some(t) => Ok(*t),
+                None => Err(TypeError::Unbound { name: name.clone() }),
+            },
+            Expr::Eq(e1, e2) => {
+                let r1 = typecheck_env(e1, env)?;
+                let r2 = typecheck_env(e2, env)?;
+                check_operands("==", IntType, r1, r2)?;
+                Ok(BoolType)
+            }
+            Expr::Lt(e1, e2) => {
+                let r1 = typecheck_env(e1, env)?;
+                let r2 = typecheck_env(e2, env)?;
+                check_operands("<", IntType, r1, r2)?;
+                Ok(BoolType)
+            }
+            Expr::If(cond, then, els) => {
+                let cond_type = typecheck_env(cond, env)?;
+                if cond_type != BoolType {
+                    return Err(TypeError::Mismatch {
+                        context: "if condition".to_string(),
+                        expected: BoolType,
+                        found: cond_type,
+                    });
                 }
+                let then_type = typecheck_env(then, env)?;
+                let els_type = typecheck_env(els, env)?;
+                if then_type != els_type {
+                    return Err(TypeError::Mismatch {
+                        context: "if branches".to_string(),
+                        expected: then_type,
+                        found: els_type,
+                    });
+                }
+                Ok(then_type)
             }
         }
     }
 
+    /*
+     * Checks that both operands of `op` have the required `operand` type,
+     * blaming the first side that does not match. On success returns the
+     * operand type, which is the result type of `+`, `*`, `||` and `&&`; the
+     * comparisons `==` and `<` discard it and yield `BoolType`.
+     */
+    fn check_operands(
+        op: &'static str,
+        operand: Type,
+        r1: Type,
+        r2: Type,
+    ) -> Result<Type, TypeError> {
+        if r1 != operand {
+            return Err(TypeError::OperandMismatch {
+                op,
+                side: Side::Left,
+                found: r1,
+            });
+        }
+        if r2 != operand {
+            return Err(TypeError::OperandMismatch {
+                op,
+                side: Side::Right,
+                found: r2,
+            });
+        }
+        Ok(operand)
+    }
+
 
     pub fn eval_t(expr: &Expr) -> Result<Either<i32, bool>, String> {
+        let env = HashMap::new();
+        eval_t_env(expr, &env)
+    }
+
+    /*
+     * Evaluates `expr` under the given environment mapping variable names to
+     * their bound values. Mirrors `typecheck_env`: `Let` evaluates its value,
+     * binds it in a child scope and evaluates the body there; `Var` reads the
+     * bound value back out.
+     */
+    fn eval_t_env(
+        expr: &Expr,
+        env: &HashMap<String, Either<i32, bool>>,
+    ) -> Result<Either<i32, bool>, String> {
         match expr {
             Expr::One => Ok(Left(1)),
             Expr::Zero => Ok(Left(0)),
             Expr::ETrue => Ok(Right(true)),
             Expr::EFalse => Ok(Right(false)),
             Expr::Plus(e1, e2) => {
-                let r1 = eval_t(e1)?;
-                let r2 = eval_t(e2)?;
+                let r1 = eval_t_env(e1, env)?;
+                let r2 = eval_t_env(e2, env)?;
                 match (r1, r2) {
                     (Left(a), Left(b)) => Ok(Left(a + b)),
                     _ => Err("Incompatible Types!".parse().unwrap()),
                 }
             }
             Expr::Mult(e1, e2) => {
-                let r1 = eval_t(e1)?;
-                let r2 = eval_t(e1)?;
+                let r1 = eval_t_env(e1, env)?;
+                let r2 = eval_t_env(e2, env)?;
                 match (r1, r2) {
                     (Left(a), Left(b)) => Ok(Left(a * b)),
                     _ => Err("Incompatible Types!".parse().unwrap()),
                 }
             }
             Expr::Or(e1, e2) => {
-                let r1 = eval_t(e1)?;
-                let r2 = eval_t(e2)?;
+                let r1 = eval_t_env(e1, env)?;
+                let r2 = eval_t_env(e2, env)?;
                 match (r1, r2) {
                     (Right(a), Right(b)) => Ok(Right(a || b)),
                     _ => Err("Incompatible Types!".parse().unwrap()),
@@ -88,13 +241,105 @@ pub mod execution {
             }
 
             Expr::And(e1, e2) => {
-                let r1 = eval_t(e1)?;
-                let r2 = eval_t(e2)?;
+                let r1 = eval_t_env(e1, env)?;
+                let r2 = eval_t_env(e2, env)?;
                 match (r1, r2) {
                     (Right(a), Right(b)) => Ok(Right(a && b)),
                     _ => Err("Incompatible Types!".parse().unwrap()),
                 }
             }
+            Let(name, value, body) => {
+                let bound = eval_t_env(value, env)?;
+                let mut child = env.clone();
+                child.insert(name.clone(), bound);
+                eval_t_env(body, &child)
+            }
+            Var(name) => match env.get(name) {
+                Some(v) => Ok(v.clone()),
+                None => Err(format!("unbound variable `{}`", name)),
+            },
+            Expr::Eq(e1, e2) => {
+                let r1 = eval_t_env(e1, env)?;
+                let r2 = eval_t_env(e2, env)?;
+                match (r1, r2) {
+                    (Left(a), Left(b)) => Ok(Right(a == b)),
+                    _ => Err("Incompatible Types!".parse().unwrap()),
+                }
+            }
+            Expr::Lt(e1, e2) => {
+                let r1 = eval_t_env(e1, env)?;
+                let r2 = eval_t_env(e2, env)?;
+                match (r1, r2) {
+                    (Left(a), Left(b)) => Ok(Right(a < b)),
+                    _ => Err("Incompatible Types!".parse().unwrap()),
+                }
+            }
+            Expr::If(cond, then, els) => {
+                let c = eval_t_env(cond, env)?;
+                match c {
+                    Right(true) => eval_t_env(then, env),
+                    Right(false) => eval_t_env(els, env),
+                    _ => Err("Incompatible Types!".parse().unwrap()),
+                }
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{eval_t, typecheck};
+        use crate::expression::expression::Expr::{Eq, ETrue, If, Lt, One, Var, Zero};
+        use crate::expression::expression::Expr::{self, Let};
+        use crate::util::util::Either::{Left, Right};
+        use crate::Type::{BoolType, IntType};
+
+        // A `let` binding types and evaluates its body under the new name.
+        #[test]
+        fn lets_bind_names() {
+            let expr = Let("x".to_string(), Box::new(One), Box::new(Var("x".to_string())));
+            assert!(matches!(typecheck(&expr), Ok(IntType)));
+            assert_eq!(eval_t(&expr), Ok(Left(1)));
+        }
+
+        // Referencing an unbound name is a type error.
+        #[test]
+        fn unbound_variable_is_rejected() {
+            let expr: Expr = Var("y".to_string());
+            assert!(typecheck(&expr).is_err());
+        }
+
+        // `let` shadows the outer binding within its body.
+        #[test]
+        fn lets_nest() {
+            let inner = Let("x".to_string(), Box::new(Zero), Box::new(Var("x".to_string())));
+            let expr = Let("x".to_string(), Box::new(One), Box::new(inner));
+            assert_eq!(eval_t(&expr), Ok(Left(0)));
+        }
+
+        // Comparisons take two ints and produce a bool.
+        #[test]
+        fn comparisons_bridge_int_to_bool() {
+            let eq = Eq(Box::new(One), Box::new(Zero));
+            assert!(matches!(typecheck(&eq), Ok(BoolType)));
+            assert_eq!(eval_t(&eq), Ok(Right(false)));
+
+            let lt = Lt(Box::new(Zero), Box::new(One));
+            assert_eq!(eval_t(&lt), Ok(Right(true)));
+        }
+
+        // `if` takes the branch selected by its boolean condition.
+        #[test]
+        fn if_selects_branch() {
+            let expr = If(Box::new(ETrue), Box::new(One), Box::new(Zero));
+            assert!(matches!(typecheck(&expr), Ok(IntType)));
+            assert_eq!(eval_t(&expr), Ok(Left(1)));
+        }
+
+        // Branches of differing types are rejected.
+        #[test]
+        fn if_branches_must_agree() {
+            let expr = If(Box::new(ETrue), Box::new(One), Box::new(ETrue));
+            assert!(typecheck(&expr).is_err());
         }
     }
 }
@@ -1,14 +1,99 @@
 pub mod util {
     use std::fmt::{Debug, Display, Formatter};
     use crate::util::util::Either::{Left, Right};
+    #[cfg(feature = "serde")]
+    use serde::{Deserialize, Serialize};
 
 
-    #[derive(Debug, PartialEq)]
+    #[derive(Debug, PartialEq, Clone)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    // Untagged so a `Left(1)` serializes as the bare value `1`.
+    #[cfg_attr(feature = "serde", serde(untagged))]
     pub enum Either<A, B> {
         Left(A),
         Right(B),
     }
 
+    impl<A, B> Either<A, B> {
+        // True if this is a `Left`.
+        pub fn is_left(&self) -> bool {
+            matches!(self, Left(_))
+        }
+
+        // True if this is a `Right`.
+        pub fn is_right(&self) -> bool {
+            matches!(self, Right(_))
+        }
+
+        // The left value, if present.
+        pub fn left(self) -> Option<A> {
+            match self {
+                Left(a) => Some(a),
+                Right(_) => None,
+            }
+        }
+
+        // The right value, if present.
+        pub fn right(self) -> Option<B> {
+            match self {
+                Left(_) => None,
+                Right(b) => Some(b),
+            }
+        }
+
+        // Transform the left value, leaving a right untouched.
+        pub fn map_left<C, F: FnOnce(A) -> C>(self, f: F) -> Either<C, B> {
+            match self {
+                Left(a) => Left(f(a)),
+                Right(b) => Right(b),
+            }
+        }
+
+        // Transform the right value, leaving a left untouched.
+        pub fn map_right<C, F: FnOnce(B) -> C>(self, f: F) -> Either<A, C> {
+            match self {
+                Left(a) => Left(a),
+                Right(b) => Right(f(b)),
+            }
+        }
+
+        // Transform whichever arm is present with its own function.
+        pub fn map_either<C, D, F: FnOnce(A) -> C, G: FnOnce(B) -> D>(
+            self,
+            f: F,
+            g: G,
+        ) -> Either<C, D> {
+            match self {
+                Left(a) => Left(f(a)),
+                Right(b) => Right(g(b)),
+            }
+        }
+
+        // Collapse both arms into a single value.
+        pub fn either<T, F: FnOnce(A) -> T, G: FnOnce(B) -> T>(self, f: F, g: G) -> T {
+            match self {
+                Left(a) => f(a),
+                Right(b) => g(b),
+            }
+        }
+
+        // Borrow the contents, turning `&Either<A, B>` into `Either<&A, &B>`.
+        pub fn as_ref(&self) -> Either<&A, &B> {
+            match self {
+                Left(a) => Left(a),
+                Right(b) => Right(b),
+            }
+        }
+
+        // Mutably borrow the contents.
+        pub fn as_mut(&mut self) -> Either<&mut A, &mut B> {
+            match self {
+                Left(a) => Left(a),
+                Right(b) => Right(b),
+            }
+        }
+    }
+
     // Make the result nicer to look at
     impl<A: Display, B: Display> Display for Either<A, B> {
         fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -18,4 +103,40 @@ pub mod util {
             }
         }
     }
+
+    #[cfg(test)]
+    mod tests {
+        use super::Either;
+        use super::Either::{Left, Right};
+
+        // The discriminant predicates and accessors agree on which arm holds.
+        #[test]
+        fn inspects_arms() {
+            let l: Either<i32, bool> = Left(1);
+            assert!(l.is_left() && !l.is_right());
+            assert_eq!(l.left(), Some(1));
+
+            let r: Either<i32, bool> = Right(true);
+            assert!(r.is_right());
+            assert_eq!(r.right(), Some(true));
+        }
+
+        // `map_*` touches only the matching arm.
+        #[test]
+        fn maps_single_arm() {
+            let mapped: Either<i32, bool> = Left::<i32, bool>(1).map_left(|a| a + 1);
+            assert_eq!(mapped, Left(2));
+            let untouched: Either<i32, bool> = Right::<i32, bool>(true).map_left(|a| a + 1);
+            assert_eq!(untouched, Right(true));
+        }
+
+        // `either` collapses both arms into one type; `as_ref` borrows without moving.
+        #[test]
+        fn collapses_and_borrows() {
+            let value: Either<i32, bool> = Left(7);
+            assert_eq!(value.as_ref().either(|a| *a, |b| *b as i32), 7);
+            // Borrowing left the original usable.
+            assert_eq!(value.either(|a| a, |b| b as i32), 7);
+        }
+    }
 }
\ No newline at end of file